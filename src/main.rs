@@ -1,5 +1,9 @@
 use rand::{rng, Rng};
-use std::{collections::VecDeque, time::Duration};
+use std::{
+	cmp::Ordering,
+	collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+	time::Duration,
+};
 
 use bevy::prelude::*;
 
@@ -11,6 +15,10 @@ const WINDOW_SIZE: Vec2 = Vec2::new(GRID_SIZE.x * GRID_PIXELS, GRID_SIZE.y * GRI
 
 const INITIAL_SEGMENTS: usize = 4;
 const TICS_PER_SECOND: f32 = 4.0;
+const MIN_TICK_PERIOD: f32 = 0.05;
+const SPEED_GROWTH_FACTOR: f32 = 0.95;
+const PHEROMONE_DECAY: f32 = 0.98;
+const PHEROMONE_DEPOSIT: f32 = 10.0;
 
 #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
 enum GameStates {
@@ -19,9 +27,22 @@ enum GameStates {
 	GameOver,
 }
 
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default)]
+enum BoundaryMode {
+	#[default]
+	Walls,
+	Wrap,
+}
+
 #[derive(Event)]
 struct FoodEated;
 
+#[derive(Resource, Default)]
+struct Score(usize);
+
+#[derive(Component)]
+struct GameOverUi;
+
 #[derive(Component)]
 struct Food;
 
@@ -64,6 +85,29 @@ struct Player {
 #[derive(Component)]
 struct Segment;
 
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+enum ForagingGoal {
+	#[default]
+	Seek,
+	Return,
+}
+
+#[derive(Component)]
+struct Competitor {
+	facing: Direction,
+	next_movement: Direction,
+	segment_positions: VecDeque<Vec2>,
+	goal: ForagingGoal,
+	history: VecDeque<(i32, i32)>,
+	home: Vec2,
+}
+
+#[derive(Component)]
+struct CompetitorSegment;
+
+#[derive(Event)]
+struct CompetitorFoodEated;
+
 #[derive(Component)]
 #[require(Transform)]
 struct GridPos(Vec2);
@@ -122,20 +166,62 @@ fn make_segment() -> impl Bundle {
 	)
 }
 
-fn get_valid_food_placement(player_pos: &Vec2, player: &Player) -> Vec2 {
+const COMPETITOR_HOME: Vec2 = Vec2::new(2.0, 2.0);
+
+fn make_competitor() -> impl Bundle {
+	(
+		Competitor {
+			facing: Direction::Right,
+			next_movement: Direction::Right,
+			segment_positions: VecDeque::with_capacity((GRID_SIZE.x * GRID_SIZE.y) as usize),
+			goal: ForagingGoal::Seek,
+			history: VecDeque::new(),
+			home: COMPETITOR_HOME,
+		},
+		GridPos(COMPETITOR_HOME),
+		Sprite::from_color(Color::srgb(1.0, 0.5, 0.0), Vec2::ONE),
+		Transform {
+			scale: Vec3 {
+				x: GRID_CONTENTS,
+				y: GRID_CONTENTS,
+				z: 1.0,
+			},
+			..default()
+		},
+	)
+}
+
+fn make_competitor_segment() -> impl Bundle {
+	(
+		Sprite::from_color(Color::srgb(1.0, 0.7, 0.4), Vec2::ONE),
+		GridPos::new(-5.0, -5.0),
+		CompetitorSegment,
+		Transform {
+			scale: Vec3 {
+				x: GRID_CONTENTS,
+				y: GRID_CONTENTS,
+				z: 1.0,
+			},
+			translation: Vec3::new(-WINDOW_SIZE.x, -WINDOW_SIZE.y, 1.0),
+			..default()
+		},
+	)
+}
+
+fn get_valid_food_placement(occupied: &[Vec2]) -> Vec2 {
 	let potential_x = rng().random_range(0..GRID_SIZE.x as i32);
 	let potential_y = rng().random_range(0..GRID_SIZE.y as i32);
 	let potential = Vec2::new(potential_x as f32, potential_y as f32);
 
-	if &potential == player_pos || player.segment_positions.iter().any(|&pos| pos == potential) {
-		return get_valid_food_placement(player_pos, player);
+	if occupied.iter().any(|&pos| pos == potential) {
+		return get_valid_food_placement(occupied);
 	}
 
 	return potential;
 }
 
-fn make_food(player_pos: &Vec2, player: &Player) -> impl Bundle {
-	let position = get_valid_food_placement(player_pos, player);
+fn make_food(occupied: &[Vec2]) -> impl Bundle {
+	let position = get_valid_food_placement(occupied);
 	(
 		Food,
 		GridPos::new(position.x, position.y),
@@ -157,20 +243,90 @@ struct TickTimer {
 	timer: Timer,
 }
 
-fn setup(mut commands: Commands) {
+#[derive(Resource)]
+struct Difficulty {
+	base_period: f32,
+	growth_factor: f32,
+	min_period: f32,
+}
+
+impl Default for Difficulty {
+	fn default() -> Self {
+		Difficulty {
+			base_period: 1.0 / TICS_PER_SECOND,
+			growth_factor: SPEED_GROWTH_FACTOR,
+			min_period: MIN_TICK_PERIOD,
+		}
+	}
+}
+
+#[derive(Resource)]
+struct PheromoneField {
+	cells: Vec<f32>,
+}
+
+impl PheromoneField {
+	fn width() -> usize {
+		GRID_SIZE.x as usize
+	}
+
+	fn index(pos: (i32, i32)) -> usize {
+		pos.1 as usize * Self::width() + pos.0 as usize
+	}
+
+	fn get(&self, pos: (i32, i32)) -> f32 {
+		self.cells[Self::index(pos)]
+	}
+
+	fn deposit(&mut self, pos: (i32, i32), amount: f32) {
+		let index = Self::index(pos);
+		self.cells[index] += amount;
+	}
+
+	fn decay(&mut self, factor: f32) {
+		for cell in &mut self.cells {
+			*cell *= factor;
+		}
+	}
+}
+
+impl Default for PheromoneField {
+	fn default() -> Self {
+		PheromoneField {
+			cells: vec![0.0; (GRID_SIZE.x * GRID_SIZE.y) as usize],
+		}
+	}
+}
+
+fn deposit_trail_pheromone(field: &mut PheromoneField, history: &VecDeque<(i32, i32)>) {
+	for (distance_from_food, &pos) in history.iter().rev().enumerate() {
+		field.deposit(pos, PHEROMONE_DEPOSIT / (1.0 + distance_from_food as f32));
+	}
+}
+
+fn spawn_dragon(commands: &mut Commands) {
+	commands.spawn(make_player());
+	for _ in 0..INITIAL_SEGMENTS {
+		commands.spawn(make_segment());
+	}
+}
+
+fn spawn_competitor(commands: &mut Commands) {
+	commands.spawn(make_competitor());
+}
+
+fn setup(mut commands: Commands, difficulty: Res<Difficulty>) {
 	commands.insert_resource(TickTimer {
 		timer: Timer::new(
-			Duration::from_secs_f32(1.0 / TICS_PER_SECOND),
+			Duration::from_secs_f32(difficulty.base_period),
 			TimerMode::Repeating,
 		),
 	});
 
 	commands.spawn(Camera2d);
 
-	commands.spawn(make_player());
-	for _ in 0..INITIAL_SEGMENTS {
-		commands.spawn(make_segment());
-	}
+	spawn_dragon(&mut commands);
+	spawn_competitor(&mut commands);
 }
 
 fn move_from_gridpos(query: Query<(&mut Transform, &GridPos)>) {
@@ -205,6 +361,44 @@ fn move_player(
 	}
 }
 
+fn apply_boundary(
+	tick_timer: Res<TickTimer>,
+	boundary_mode: Res<BoundaryMode>,
+	mut player_pos: Single<&mut GridPos, With<Player>>,
+	mut next_state: ResMut<NextState<GameStates>>,
+) {
+	if !tick_timer.timer.finished() {
+		return;
+	}
+	match *boundary_mode {
+		BoundaryMode::Wrap => {
+			player_pos.0.x = player_pos.0.x.rem_euclid(GRID_SIZE.x);
+			player_pos.0.y = player_pos.0.y.rem_euclid(GRID_SIZE.y);
+		}
+		BoundaryMode::Walls => {
+			if !(0.0..GRID_SIZE.x).contains(&player_pos.0.x)
+				|| !(0.0..GRID_SIZE.y).contains(&player_pos.0.y)
+			{
+				next_state.set(GameStates::GameOver);
+			}
+		}
+	}
+}
+
+fn speed_up_on_food(
+	difficulty: Res<Difficulty>,
+	mut tick_timer: ResMut<TickTimer>,
+	mut food_eated_reader: EventReader<FoodEated>,
+) {
+	for _ in food_eated_reader.read() {
+		let next_period = (tick_timer.timer.duration().as_secs_f32() * difficulty.growth_factor)
+			.max(difficulty.min_period);
+		tick_timer
+			.timer
+			.set_duration(Duration::from_secs_f32(next_period));
+	}
+}
+
 fn move_segments(
 	tick_timer: Res<TickTimer>,
 	player: Single<&Player>,
@@ -242,6 +436,7 @@ fn handle_food_eating(
 	food: Single<(&GridPos, Entity), With<Food>>,
 	mut commands: Commands,
 	mut food_eated_writer: EventWriter<FoodEated>,
+	mut score: ResMut<Score>,
 ) {
 	let (food_pos, food_entity) = food.into_inner();
 	if player_pos.0 != food_pos.0 {
@@ -250,10 +445,12 @@ fn handle_food_eating(
 	commands.entity(food_entity).despawn();
 	commands.spawn(make_segment());
 	food_eated_writer.write(FoodEated);
+	score.0 += 1;
 }
 
 fn spawn_food_if_needed(
 	player_query: Single<(&GridPos, &Player)>,
+	competitor_query: Single<(&GridPos, &Competitor)>,
 	existing_foods: Query<&Food>,
 	mut commands: Commands,
 ) {
@@ -262,10 +459,373 @@ fn spawn_food_if_needed(
 	}
 
 	let (player_pos, player) = player_query.into_inner();
-	let new_food = make_food(&player_pos.0, &player);
+	let (competitor_pos, competitor) = competitor_query.into_inner();
+
+	let mut occupied: Vec<Vec2> = player.segment_positions.iter().copied().collect();
+	occupied.push(player_pos.0);
+	occupied.extend(competitor.segment_positions.iter().copied());
+	occupied.push(competitor_pos.0);
+
+	let new_food = make_food(&occupied);
 	commands.spawn(new_food);
 }
 
+fn decay_pheromone(tick_timer: Res<TickTimer>, mut pheromone_field: ResMut<PheromoneField>) {
+	if !tick_timer.timer.finished() {
+		return;
+	}
+	pheromone_field.decay(PHEROMONE_DECAY);
+}
+
+fn strongest_neighbor(
+	pos: (i32, i32),
+	field: &PheromoneField,
+	blocked: &HashSet<(i32, i32)>,
+) -> Option<(i32, i32)> {
+	let candidates: Vec<(i32, i32)> = neighbors(pos)
+		.into_iter()
+		.filter(|neighbor| in_bounds(*neighbor) && !blocked.contains(neighbor))
+		.collect();
+	let best_scent = candidates
+		.iter()
+		.map(|&neighbor| field.get(neighbor))
+		.fold(f32::MIN, f32::max);
+	let strongest: Vec<(i32, i32)> = candidates
+		.into_iter()
+		.filter(|&neighbor| field.get(neighbor) == best_scent)
+		.collect();
+	if strongest.is_empty() {
+		return None;
+	}
+	strongest.get(rng().random_range(0..strongest.len())).copied()
+}
+
+fn closest_to_neighbor(
+	pos: (i32, i32),
+	target: (i32, i32),
+	blocked: &HashSet<(i32, i32)>,
+) -> Option<(i32, i32)> {
+	let candidates: Vec<(i32, i32)> = neighbors(pos)
+		.into_iter()
+		.filter(|neighbor| in_bounds(*neighbor) && !blocked.contains(neighbor))
+		.collect();
+	let best_distance = candidates
+		.iter()
+		.map(|&neighbor| manhattan(neighbor, target))
+		.min()?;
+	let closest: Vec<(i32, i32)> = candidates
+		.into_iter()
+		.filter(|&neighbor| manhattan(neighbor, target) == best_distance)
+		.collect();
+	closest.get(rng().random_range(0..closest.len())).copied()
+}
+
+fn move_competitor(
+	tick_timer: Res<TickTimer>,
+	pheromone_field: Res<PheromoneField>,
+	competitor_query: Single<(&mut GridPos, &mut Competitor)>,
+	mut eated_events: ResMut<Events<CompetitorFoodEated>>,
+) {
+	if !tick_timer.timer.finished() {
+		return;
+	}
+	let mut just_ate = false;
+	for _ in eated_events.drain() {
+		just_ate = true;
+	}
+
+	let (mut competitor_pos, mut competitor) = competitor_query.into_inner();
+	let current = (competitor_pos.0.x as i32, competitor_pos.0.y as i32);
+
+	let tail = competitor.segment_positions.front().copied();
+	let blocked: HashSet<(i32, i32)> = competitor
+		.segment_positions
+		.iter()
+		.filter(|&&pos| Some(pos) != tail)
+		.map(|pos| (pos.x as i32, pos.y as i32))
+		.collect();
+
+	let next = match competitor.goal {
+		ForagingGoal::Seek => strongest_neighbor(current, &pheromone_field, &blocked),
+		ForagingGoal::Return => {
+			let home = (competitor.home.x as i32, competitor.home.y as i32);
+			closest_to_neighbor(current, home, &blocked)
+		}
+	};
+	let Some(next) = next else {
+		return;
+	};
+
+	if let Some(direction) = delta_to_direction((next.0 - current.0, next.1 - current.1)) {
+		competitor.next_movement = direction;
+	}
+	competitor.facing = competitor.next_movement.clone();
+	competitor.segment_positions.push_back(competitor_pos.0);
+	competitor_pos.0 = Vec2::new(next.0 as f32, next.1 as f32);
+	if !just_ate {
+		competitor.segment_positions.pop_front();
+	}
+
+	if competitor.goal == ForagingGoal::Seek {
+		competitor.history.push_back(current);
+	} else if next == (competitor.home.x as i32, competitor.home.y as i32) {
+		competitor.goal = ForagingGoal::Seek;
+	}
+}
+
+fn move_competitor_segments(
+	tick_timer: Res<TickTimer>,
+	competitor: Single<&Competitor>,
+	segments: Query<&mut GridPos, With<CompetitorSegment>>,
+) {
+	if !tick_timer.timer.finished() {
+		return;
+	}
+	for (index, mut segment_pos) in segments.into_iter().enumerate() {
+		if let Some(pos) = competitor.segment_positions.get(index) {
+			segment_pos.0.x = pos.x;
+			segment_pos.0.y = pos.y;
+		}
+	}
+}
+
+fn handle_competitor_food_eating(
+	player_pos: Single<&GridPos, With<Player>>,
+	competitor_pos: Single<&GridPos, With<Competitor>>,
+	mut competitor: Single<&mut Competitor>,
+	food: Single<(&GridPos, Entity), With<Food>>,
+	mut commands: Commands,
+	mut eated_writer: EventWriter<CompetitorFoodEated>,
+	mut pheromone_field: ResMut<PheromoneField>,
+) {
+	let (food_pos, food_entity) = food.into_inner();
+	if competitor_pos.0 != food_pos.0 {
+		return;
+	}
+	if player_pos.0 == food_pos.0 {
+		// The player reached the same food this tick; handle_food_eating already
+		// queued its despawn, so let the player win the tie instead of double-feeding.
+		return;
+	}
+	commands.entity(food_entity).despawn();
+	commands.spawn(make_competitor_segment());
+	eated_writer.write(CompetitorFoodEated);
+
+	deposit_trail_pheromone(&mut pheromone_field, &competitor.history);
+	competitor.history.clear();
+	competitor.goal = ForagingGoal::Return;
+}
+
+fn check_competitor_collision(
+	tick_timer: Res<TickTimer>,
+	player_pos: Single<&GridPos, With<Player>>,
+	competitor_pos: Single<&GridPos, With<Competitor>>,
+	competitor_segments: Query<&GridPos, With<CompetitorSegment>>,
+	mut next_state: ResMut<NextState<GameStates>>,
+) {
+	if !tick_timer.timer.finished() {
+		return;
+	}
+	if player_pos.0 == competitor_pos.0 {
+		next_state.set(GameStates::GameOver);
+	}
+	for segment_pos in competitor_segments {
+		if segment_pos.0 == player_pos.0 {
+			next_state.set(GameStates::GameOver);
+		}
+	}
+}
+
+#[derive(Resource, Default)]
+struct Autopilot(bool);
+
+fn autopilot_enabled(autopilot: Res<Autopilot>) -> bool {
+	autopilot.0
+}
+
+fn toggle_autopilot(keyboard_input: Res<ButtonInput<KeyCode>>, mut autopilot: ResMut<Autopilot>) {
+	if keyboard_input.just_pressed(KeyCode::KeyP) {
+		autopilot.0 = !autopilot.0;
+	}
+}
+
+fn in_bounds(pos: (i32, i32)) -> bool {
+	(0..GRID_SIZE.x as i32).contains(&pos.0) && (0..GRID_SIZE.y as i32).contains(&pos.1)
+}
+
+fn neighbors(pos: (i32, i32)) -> [(i32, i32); 4] {
+	[
+		(pos.0, pos.1 + 1),
+		(pos.0, pos.1 - 1),
+		(pos.0 - 1, pos.1),
+		(pos.0 + 1, pos.1),
+	]
+}
+
+fn manhattan(a: (i32, i32), b: (i32, i32)) -> i32 {
+	(a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+fn delta_to_direction(delta: (i32, i32)) -> Option<Direction> {
+	match delta {
+		(0, 1) => Some(Direction::Up),
+		(0, -1) => Some(Direction::Down),
+		(-1, 0) => Some(Direction::Left),
+		(1, 0) => Some(Direction::Right),
+		_ => None,
+	}
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct AstarNode {
+	cost: i32,
+	pos: (i32, i32),
+}
+
+impl Ord for AstarNode {
+	fn cmp(&self, other: &Self) -> Ordering {
+		// BinaryHeap is a max-heap, so reverse to pop the lowest cost first.
+		other.cost.cmp(&self.cost)
+	}
+}
+
+impl PartialOrd for AstarNode {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+fn reconstruct_path(
+	came_from: &HashMap<(i32, i32), (i32, i32)>,
+	mut current: (i32, i32),
+) -> Vec<(i32, i32)> {
+	let mut path = vec![current];
+	while let Some(&prev) = came_from.get(&current) {
+		current = prev;
+		path.push(current);
+	}
+	path.reverse();
+	path
+}
+
+fn astar_path(
+	start: (i32, i32),
+	goal: (i32, i32),
+	blocked: &HashSet<(i32, i32)>,
+) -> Option<Vec<(i32, i32)>> {
+	let mut open = BinaryHeap::new();
+	open.push(AstarNode {
+		cost: manhattan(start, goal),
+		pos: start,
+	});
+
+	let mut came_from: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+	let mut g_score: HashMap<(i32, i32), i32> = HashMap::new();
+	g_score.insert(start, 0);
+
+	while let Some(AstarNode { pos, .. }) = open.pop() {
+		if pos == goal {
+			return Some(reconstruct_path(&came_from, pos));
+		}
+		let current_g = g_score[&pos];
+		for neighbor in neighbors(pos) {
+			if !in_bounds(neighbor) || blocked.contains(&neighbor) {
+				continue;
+			}
+			let tentative_g = current_g + 1;
+			if tentative_g < *g_score.get(&neighbor).unwrap_or(&i32::MAX) {
+				came_from.insert(neighbor, pos);
+				g_score.insert(neighbor, tentative_g);
+				open.push(AstarNode {
+					cost: tentative_g + manhattan(neighbor, goal),
+					pos: neighbor,
+				});
+			}
+		}
+	}
+	None
+}
+
+fn flood_fill_size(start: (i32, i32), blocked: &HashSet<(i32, i32)>) -> usize {
+	let mut visited = HashSet::new();
+	let mut queue = VecDeque::new();
+	visited.insert(start);
+	queue.push_back(start);
+	while let Some(pos) = queue.pop_front() {
+		for neighbor in neighbors(pos) {
+			if in_bounds(neighbor) && !blocked.contains(&neighbor) && visited.insert(neighbor) {
+				queue.push_back(neighbor);
+			}
+		}
+	}
+	visited.len()
+}
+
+fn best_survival_move(
+	start: (i32, i32),
+	blocked: &HashSet<(i32, i32)>,
+	facing: &Direction,
+) -> Option<Direction> {
+	let inverse = facing.inverse();
+	[
+		Direction::Up,
+		Direction::Down,
+		Direction::Left,
+		Direction::Right,
+	]
+	.into_iter()
+	.filter(|direction| *direction != inverse)
+	.filter_map(|direction| {
+		let delta = direction.to_vec2();
+		let candidate = (start.0 + delta.x as i32, start.1 + delta.y as i32);
+		if !in_bounds(candidate) || blocked.contains(&candidate) {
+			return None;
+		}
+		Some((direction, flood_fill_size(candidate, blocked)))
+	})
+	.max_by_key(|(_, size)| *size)
+	.map(|(direction, _)| direction)
+}
+
+fn autopilot_steer(
+	tick_timer: Res<TickTimer>,
+	player_query: Single<(&GridPos, &mut Player)>,
+	food_pos: Single<&GridPos, With<Food>>,
+	competitor_pos: Single<&GridPos, With<Competitor>>,
+	competitor_segments: Query<&GridPos, With<CompetitorSegment>>,
+) {
+	if !tick_timer.timer.finished() {
+		return;
+	}
+	let (player_pos, mut player) = player_query.into_inner();
+	let start = (player_pos.0.x as i32, player_pos.0.y as i32);
+	let goal = (food_pos.0.x as i32, food_pos.0.y as i32);
+
+	let tail = player.segment_positions.front().copied();
+	let mut blocked: HashSet<(i32, i32)> = player
+		.segment_positions
+		.iter()
+		.filter(|&&pos| Some(pos) != tail)
+		.map(|pos| (pos.x as i32, pos.y as i32))
+		.collect();
+	blocked.insert((competitor_pos.0.x as i32, competitor_pos.0.y as i32));
+	blocked.extend(
+		competitor_segments
+			.iter()
+			.map(|pos| (pos.0.x as i32, pos.0.y as i32)),
+	);
+
+	let next_direction = astar_path(start, goal, &blocked)
+		.and_then(|path| path.get(1).copied())
+		.and_then(|(x, y)| delta_to_direction((x - start.0, y - start.1)))
+		.filter(|direction| *direction != player.facing.inverse())
+		.or_else(|| best_survival_move(start, &blocked, &player.facing));
+
+	if let Some(direction) = next_direction {
+		player.next_movement = direction;
+	}
+}
+
 fn handle_inputs(keyboard_input: Res<ButtonInput<KeyCode>>, mut player: Single<&mut Player>) {
 	let mut new_direction: Option<Direction> = None;
 	if keyboard_input.just_pressed(KeyCode::KeyW) || keyboard_input.just_pressed(KeyCode::ArrowUp) {
@@ -292,6 +852,83 @@ fn handle_inputs(keyboard_input: Res<ButtonInput<KeyCode>>, mut player: Single<&
 	}
 }
 
+fn spawn_game_over_screen(mut commands: Commands, score: Res<Score>) {
+	let segment_count = INITIAL_SEGMENTS + score.0;
+	commands
+		.spawn((
+			GameOverUi,
+			Node {
+				width: Val::Percent(100.0),
+				height: Val::Percent(100.0),
+				flex_direction: FlexDirection::Column,
+				align_items: AlignItems::Center,
+				justify_content: JustifyContent::Center,
+				row_gap: Val::Px(10.0),
+				..default()
+			},
+		))
+		.with_children(|parent| {
+			parent.spawn((
+				Text::new(format!("Game over! Score: {}", segment_count)),
+				TextFont {
+					font_size: 40.0,
+					..default()
+				},
+			));
+			parent.spawn(Text::new("Press space to restart"));
+		});
+}
+
+fn restart_on_input(
+	keyboard_input: Res<ButtonInput<KeyCode>>,
+	mut commands: Commands,
+	mut score: ResMut<Score>,
+	mut tick_timer: ResMut<TickTimer>,
+	difficulty: Res<Difficulty>,
+	mut pheromone_field: ResMut<PheromoneField>,
+	mut next_state: ResMut<NextState<GameStates>>,
+	players: Query<Entity, With<Player>>,
+	segments: Query<Entity, With<Segment>>,
+	competitors: Query<Entity, With<Competitor>>,
+	competitor_segments: Query<Entity, With<CompetitorSegment>>,
+	foods: Query<Entity, With<Food>>,
+	overlays: Query<Entity, With<GameOverUi>>,
+) {
+	if !keyboard_input.just_pressed(KeyCode::Space) {
+		return;
+	}
+	for entity in &players {
+		commands.entity(entity).despawn();
+	}
+	for entity in &segments {
+		commands.entity(entity).despawn();
+	}
+	for entity in &competitors {
+		commands.entity(entity).despawn();
+	}
+	for entity in &competitor_segments {
+		commands.entity(entity).despawn();
+	}
+	for entity in &foods {
+		commands.entity(entity).despawn();
+	}
+	for entity in &overlays {
+		commands.entity(entity).despawn();
+	}
+
+	score.0 = 0;
+	tick_timer
+		.timer
+		.set_duration(Duration::from_secs_f32(difficulty.base_period));
+	tick_timer.timer.reset();
+	*pheromone_field = PheromoneField::default();
+
+	spawn_dragon(&mut commands);
+	spawn_competitor(&mut commands);
+
+	next_state.set(GameStates::InGame);
+}
+
 fn main() {
 	App::new()
 		.add_plugins(DefaultPlugins.set(WindowPlugin {
@@ -304,7 +941,18 @@ fn main() {
 			..default()
 		}))
 		.init_resource::<Events<FoodEated>>()
+		.init_resource::<Events<CompetitorFoodEated>>()
+		.init_resource::<Autopilot>()
+		.init_resource::<BoundaryMode>()
+		.init_resource::<Score>()
+		.init_resource::<Difficulty>()
+		.init_resource::<PheromoneField>()
 		.add_systems(Startup, setup)
+		.add_systems(OnEnter(GameStates::GameOver), spawn_game_over_screen)
+		.add_systems(
+			Update,
+			restart_on_input.run_if(in_state(GameStates::GameOver)),
+		)
 		.add_systems(
 			FixedUpdate,
 			(
@@ -312,10 +960,18 @@ fn main() {
 				(
 					process_tick,
 					((
+						autopilot_steer.run_if(autopilot_enabled),
+						speed_up_on_food,
+						decay_pheromone,
 						move_player,
+						move_competitor,
+						apply_boundary,
 						handle_food_eating,
+						handle_competitor_food_eating,
 						move_segments,
+						move_competitor_segments,
 						(check_self_intersect, handle_food_eating),
+						check_competitor_collision,
 						spawn_food_if_needed,
 					)
 						.chain()
@@ -324,7 +980,10 @@ fn main() {
 					.run_if(in_state(GameStates::InGame)),
 			),
 		)
-		.add_systems(Update, handle_inputs.run_if(in_state(GameStates::InGame)))
+		.add_systems(
+			Update,
+			(handle_inputs, toggle_autopilot).run_if(in_state(GameStates::InGame)),
+		)
 		.init_state::<GameStates>()
 		.run();
 }